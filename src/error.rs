@@ -1,6 +1,9 @@
 use core::error;
 use core::fmt;
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
 use crate::header;
 #[cfg(feature = "alloc")]
 use crate::header::MaxSizeReached;
@@ -22,6 +25,32 @@ pub struct Error {
 /// A `Result` typedef to use with the `http::Error` type
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// A stable tag identifying the source of an `http::Error`.
+///
+/// This lets consumers branch on the kind of error without downcasting
+/// `get_ref()` against every concrete error type the crate exposes, and
+/// without needing to know which `cfg` feature produced it.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// The error originated from an invalid `StatusCode`.
+    StatusCode,
+    /// The error originated from an invalid `Method`.
+    Method,
+    /// The error originated from an invalid `Uri`.
+    Uri,
+    /// The error originated from invalid `Parts` of a `Uri`.
+    UriParts,
+    /// The error originated from an invalid header name.
+    HeaderName,
+    /// The error originated from an invalid header value.
+    HeaderValue,
+    /// The error originated from exceeding a configured maximum size.
+    MaxSizeReached,
+    /// The error was supplied by the caller via `Error::new`.
+    Custom,
+}
+
 enum ErrorKind {
     StatusCode(status::InvalidStatusCode),
     Method(method::InvalidMethod),
@@ -34,6 +63,8 @@ enum ErrorKind {
     HeaderValue(header::InvalidHeaderValue),
     #[cfg(feature = "alloc")]
     MaxSizeReached(MaxSizeReached),
+    #[cfg(feature = "alloc")]
+    Custom(Box<dyn error::Error + Send + Sync>),
 }
 
 impl fmt::Debug for Error {
@@ -57,6 +88,32 @@ impl Error {
         self.get_ref().is::<T>()
     }
 
+    /// Returns the category of this error.
+    ///
+    /// Unlike `get_ref`, this does not require downcasting to discriminate
+    /// between the different kinds of error this crate produces, and the
+    /// returned tag stays the same regardless of which `cfg` features are
+    /// enabled.
+    pub fn category(&self) -> ErrorCategory {
+        use self::ErrorKind::*;
+
+        match self.inner {
+            StatusCode(_) => ErrorCategory::StatusCode,
+            Method(_) => ErrorCategory::Method,
+            #[cfg(feature = "alloc")]
+            Uri(_) => ErrorCategory::Uri,
+            #[cfg(feature = "alloc")]
+            UriParts(_) => ErrorCategory::UriParts,
+            HeaderName(_) => ErrorCategory::HeaderName,
+            #[cfg(feature = "alloc")]
+            HeaderValue(_) => ErrorCategory::HeaderValue,
+            #[cfg(feature = "alloc")]
+            MaxSizeReached(_) => ErrorCategory::MaxSizeReached,
+            #[cfg(feature = "alloc")]
+            Custom(_) => ErrorCategory::Custom,
+        }
+    }
+
     /// Return a reference to the lower level, inner error.
     pub fn get_ref(&self) -> &(dyn error::Error + 'static) {
         use self::ErrorKind::*;
@@ -73,6 +130,25 @@ impl Error {
             HeaderValue(ref e) => e,
             #[cfg(feature = "alloc")]
             MaxSizeReached(ref e) => e,
+            #[cfg(feature = "alloc")]
+            Custom(ref e) => e.as_ref(),
+        }
+    }
+
+    /// Construct a new opaque `http::Error` wrapping an arbitrary error.
+    ///
+    /// This is useful for consumers who have their own error type which
+    /// wraps an `http::Error` and want it to be converted back to
+    /// `http::Error` through the `?` operator. `is`, `get_ref`, and
+    /// `downcast_ref` continue to work against the wrapped error's concrete
+    /// type.
+    #[cfg(feature = "alloc")]
+    pub fn new<E>(err: E) -> Error
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Error {
+            inner: ErrorKind::Custom(err.into()),
         }
     }
 }
@@ -172,4 +248,35 @@ mod tests {
             panic!("Bad status allowed!");
         }
     }
+
+    #[test]
+    fn category_matches_invalid_status_code() {
+        if let Err(e) = status::StatusCode::from_u16(6666) {
+            let err: Error = e.into();
+            assert_eq!(err.category(), ErrorCategory::StatusCode);
+        } else {
+            panic!("Bad status allowed!");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn custom_error_roundtrips() {
+        #[derive(Debug)]
+        struct MyError;
+
+        impl fmt::Display for MyError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("my error")
+            }
+        }
+
+        impl error::Error for MyError {}
+
+        let err = Error::new(MyError);
+        assert_eq!(err.category(), ErrorCategory::Custom);
+        assert!(err.is::<MyError>());
+        err.get_ref().downcast_ref::<MyError>().unwrap();
+        assert_eq!(err.to_string(), "my error");
+    }
 }